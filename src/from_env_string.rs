@@ -0,0 +1,79 @@
+//! [`FromEnvString`], an alternative to [`FromStr`] for values that need
+//! env-specific parsing rules (case-insensitive matching, stripping quotes,
+//! etc.) without giving the type a `FromStr` impl.
+
+use std::str::FromStr;
+
+/// Parses a value out of a raw environment variable string.
+///
+/// This exists alongside [`FromStr`] rather than replacing it: a type's
+/// `FromStr` impl is often meant for strict, canonical parsing (e.g.
+/// round-tripping with [`Display`](std::fmt::Display)), while env values are
+/// frequently looser — mixed-case enum tags, surrounding quotes, etc.
+/// A local type that does not implement `FromStr` can implement
+/// `FromEnvString` directly with those looser rules.
+///
+/// Every `T: FromStr` gets a blanket impl that just defers to `FromStr`, so
+/// existing types keep working with the [`EnvGetter`](crate::EnvGetter)
+/// `evar` method family without any changes. Because of that blanket impl, a
+/// type that implements `FromStr` cannot also have its own `FromEnvString`
+/// impl: the two would conflict.
+pub trait FromEnvString: Sized {
+    type Err;
+
+    /// Parses `s` into `Self`.
+    ///
+    /// # Errors
+    /// When `s` doesn't represent a valid `Self`.
+    fn from_env_string(s: &str) -> Result<Self, Self::Err>;
+}
+
+impl<T: FromStr> FromEnvString for T {
+    type Err = T::Err;
+
+    fn from_env_string(s: &str) -> Result<Self, Self::Err> {
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Platform {
+        PayPal,
+        Stripe,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct UnknownPlatform(String);
+
+    impl FromEnvString for Platform {
+        type Err = UnknownPlatform;
+
+        fn from_env_string(s: &str) -> Result<Self, Self::Err> {
+            match s.to_lowercase().as_str() {
+                "paypal" => Ok(Platform::PayPal),
+                "stripe" => Ok(Platform::Stripe),
+                other => Err(UnknownPlatform(other.to_string())),
+            }
+        }
+    }
+
+    #[test]
+    fn test_blanket_impl_defers_to_from_str() {
+        assert_eq!(i32::from_env_string("42"), Ok(42));
+        assert!(i32::from_env_string("not_an_int").is_err());
+    }
+
+    #[test]
+    fn test_custom_impl_is_case_insensitive() {
+        assert_eq!(Platform::from_env_string("PayPal"), Ok(Platform::PayPal));
+        assert_eq!(Platform::from_env_string("stripe"), Ok(Platform::Stripe));
+        assert_eq!(
+            Platform::from_env_string("venmo"),
+            Err(UnknownPlatform("venmo".to_string()))
+        );
+    }
+}
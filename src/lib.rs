@@ -7,6 +7,21 @@ use std::sync::{LazyLock, OnceLock};
 #[cfg(test)]
 mod tests;
 
+mod macros;
+
+#[doc(hidden)]
+pub use macros::{__env_config_name, __env_config_prefix};
+
+mod from_env_string;
+
+pub use from_env_string::FromEnvString;
+
+#[cfg(feature = "structured-values")]
+mod struct_value;
+
+#[cfg(feature = "structured-values")]
+pub use struct_value::{EnvValue, ParseError};
+
 /// Struct that holds a closure to get environment variables.
 ///
 /// # Examples
@@ -99,6 +114,50 @@ impl<G, I: Fn(&str) -> Result<String, G>> EnvGetter<G, I> {
         self.owned_var_try(name).unwrap_or_else(|_| default())
     }
 
+    /// Like [`Self::owned_var_or`], but also persists the default back into the
+    /// process environment via [`std::env::set_var`] when the variable is
+    /// missing, so child processes and later reads see the same value.
+    ///
+    /// This writes to the real process environment even though the getter
+    /// closure `I` can read from an arbitrary source. If your getter isn't backed by
+    /// `std::env`, use [`Self::owned_var_or_set_with`] to capture the write
+    /// yourself (e.g. into a test map).
+    ///
+    /// # Panics
+    /// When the environment variable is present but fails to parse for T.
+    pub fn owned_var_or_set<T: FromStr + ToString>(&self, name: &str, default: T) -> T {
+        self.owned_var_or_set_with(name, default, |name, value| {
+            // SAFETY: this crate does not itself spawn threads that read or write
+            // the environment concurrently; callers reaching for this helper accept
+            // the same single-threaded-mutation caveat as `std::env::set_var` itself.
+            unsafe { std::env::set_var(name, value) };
+        })
+    }
+
+    /// Like [`Self::owned_var_or_set`], but takes a `setter` closure instead of
+    /// always writing to [`std::env::set_var`]. Useful for custom backends
+    /// (e.g. a test map) that should also observe the default being filled in.
+    ///
+    /// # Panics
+    /// When the environment variable is present but fails to parse for T.
+    pub fn owned_var_or_set_with<T: FromStr + ToString>(
+        &self,
+        name: &str,
+        default: T,
+        mut setter: impl FnMut(&str, &str),
+    ) -> T {
+        match self.owned_var_try::<T>(name) {
+            Ok(value) => value,
+            Err(EnvError::GetterError(_)) => {
+                setter(name, &default.to_string());
+                default
+            }
+            Err(EnvError::ParseError(_)) => {
+                panic!("Couldn't find or parse env variable {name} for given type")
+            }
+        }
+    }
+
     /// Utility to attempt leaking a Box to your desired static reference type.
     fn leak<T>(to_leak: T) -> &'static T {
         Box::leak(Box::new(to_leak))
@@ -160,6 +219,222 @@ impl<G, I: Fn(&str) -> Result<String, G>> EnvGetter<G, I> {
     ) -> &'static T {
         self.var_or(name, Box::leak(default().into()))
     }
+
+    /// Useful when a single environment variable holds a delimited list, e.g.
+    /// `HOSTS=a, b, c`. Splits the raw value on `sep`, trims each segment, and
+    /// parses each one into `T`, short-circuiting on the first [`FromStr`] error.
+    ///
+    /// An empty variable yields an empty `Vec`. Segments that are empty after
+    /// trimming are skipped, so trailing/duplicate separators don't produce
+    /// spurious elements.
+    ///
+    /// The leaking version of this is [`Self::vec_var_try`].
+    ///
+    /// # Errors
+    /// When the environment variable is not found or when parsing fails for any segment.
+    pub fn owned_vec_var_try<T: FromStr>(
+        &self,
+        name: &str,
+        sep: &str,
+    ) -> Result<Vec<T>, EnvError<G, T::Err>> {
+        let var = (self.getter)(name).map_err(EnvError::GetterError)?;
+        if var.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        var.split(sep)
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.parse::<T>().map_err(EnvError::ParseError))
+            .collect()
+    }
+
+    /// Useful when your program requires a delimited-list variable to be defined
+    /// and cannot provide a default alternative, but you do not want the parsed
+    /// result to be leaked/static ref.
+    ///
+    /// The leaking version of this is [`Self::vec_var`].
+    ///
+    /// # Panics
+    /// When the environment variable is not found or when parsing fails for any segment.
+    pub fn owned_vec_var<T: FromStr>(&self, name: &str, sep: &str) -> Vec<T> {
+        self.owned_vec_var_try(name, sep).unwrap_or_else(|_| {
+            panic!("Couldn't find or parse env variable {name} for given type")
+        })
+    }
+
+    /// Useful when you want to provide a default list for the environment variable,
+    /// but you do not want the parsed result to be leaked or static.
+    ///
+    /// The leaking version of this function is [`Self::vec_var_or`].
+    pub fn owned_vec_var_or<T: FromStr>(
+        &self,
+        name: &str,
+        sep: &str,
+        default: Vec<T>,
+    ) -> Vec<T> {
+        self.owned_vec_var_try(name, sep).unwrap_or(default)
+    }
+
+    /// Useful when you want to handle the Result yourself.
+    ///
+    /// # Leaks
+    /// This function will leak the parsed vec, if any.
+    ///
+    /// # Errors
+    /// This function will error if it fails to parse any segment, or the environment
+    /// variable is not found
+    pub fn vec_var_try<T: FromStr>(
+        &self,
+        name: &str,
+        sep: &str,
+    ) -> Result<&'static [T], EnvError<G, T::Err>> {
+        self.owned_vec_var_try::<T>(name, sep)
+            .map(|vec| Self::leak(vec).as_slice())
+    }
+
+    /// Useful when your program requires a delimited-list variable to be defined
+    /// and cannot provide a default alternative.
+    ///
+    /// # Leaks
+    /// This function will leak the parsed vec.
+    ///
+    /// # Panics
+    /// When the environment variable is not found or when parsing fails for any segment.
+    pub fn vec_var<T: FromStr>(&self, name: &str, sep: &str) -> &'static [T] {
+        self.vec_var_try(name, sep).unwrap_or_else(|_| {
+            panic!("Couldn't find or parse env variable {name} for given type")
+        })
+    }
+
+    /// Useful when you want to provide a default list for the environment variable,
+    /// and you have a static reference to your default value.
+    ///
+    /// # Leaks
+    /// This function will leak the parsed value.
+    pub fn vec_var_or<T: FromStr>(
+        &self,
+        name: &str,
+        sep: &str,
+        default: &'static [T],
+    ) -> &'static [T] {
+        self.vec_var_try(name, sep).unwrap_or(default)
+    }
+}
+
+impl<G, I: Fn(&str) -> Result<String, G>> EnvGetter<G, I> {
+    /// Like [`Self::owned_var_try`], but bounded by [`FromEnvString`] instead
+    /// of [`FromStr`]. Useful for env-specific parsing rules `FromStr` can't
+    /// or shouldn't express, e.g. case-insensitive enum tags.
+    ///
+    /// The leaking version of this is [`Self::evar_try`].
+    ///
+    /// # Errors
+    /// When the environment variable is not found or when parsing fails for T.
+    pub fn owned_evar_try<T: FromEnvString>(
+        &self,
+        name: &str,
+    ) -> Result<T, EnvError<G, T::Err>> {
+        let var = (self.getter)(name).map_err(EnvError::GetterError)?;
+        T::from_env_string(&var).map_err(EnvError::ParseError)
+    }
+
+    /// Like [`Self::owned_var`], but bounded by [`FromEnvString`].
+    ///
+    /// The leaking version of this is [`Self::evar`].
+    ///
+    /// # Panics
+    /// When the environment variable is not found or when the parsing fails for T.
+    pub fn owned_evar<T: FromEnvString>(&self, name: &str) -> T {
+        self.owned_evar_try(name).unwrap_or_else(|_| {
+            panic!("Couldn't find or parse env variable {name} for given type")
+        })
+    }
+
+    /// Like [`Self::owned_var_or`], but bounded by [`FromEnvString`].
+    ///
+    /// The leaking version of this function is [`Self::evar_or`].
+    pub fn owned_evar_or<T: FromEnvString>(&self, name: &str, default: T) -> T {
+        self.owned_evar_try(name).unwrap_or(default)
+    }
+
+    /// Like [`Self::owned_var_or_else`], but bounded by [`FromEnvString`].
+    ///
+    /// The leaking version of this function is [`Self::evar_or_else`].
+    pub fn owned_evar_or_else<T: FromEnvString, V: FnOnce() -> T>(
+        &self,
+        name: &str,
+        default: V,
+    ) -> T {
+        self.owned_evar_try(name).unwrap_or_else(|_| default())
+    }
+
+    /// Like [`Self::var_try`], but bounded by [`FromEnvString`].
+    ///
+    /// # Leaks
+    /// This function will leak the parsed value, if any.
+    ///
+    /// # Errors
+    /// This function will error if it fails to parse the value, or the environment variable
+    /// is not found
+    pub fn evar_try<T: FromEnvString>(
+        &self,
+        name: &str,
+    ) -> Result<&'static T, EnvError<G, T::Err>> {
+        self.owned_evar_try::<T>(name).map(Self::leak)
+    }
+
+    /// Like [`Self::var`], but bounded by [`FromEnvString`].
+    ///
+    /// # Leaks
+    /// This function will leak the parsed value.
+    ///
+    /// # Panics
+    /// When the environment variable is not found or when the parsing fails for T.
+    pub fn evar<T: FromEnvString>(&self, name: &str) -> &'static T {
+        self.evar_try(name).unwrap_or_else(|_| {
+            panic!("Couldn't find or parse env variable {name} for given type")
+        })
+    }
+
+    /// Like [`Self::var_or`], but bounded by [`FromEnvString`].
+    ///
+    /// # Leaks
+    /// This function will leak the parsed value.
+    pub fn evar_or<T: FromEnvString>(&self, name: &str, default: &'static T) -> &'static T {
+        self.evar_try(name).unwrap_or(default)
+    }
+
+    /// Like [`Self::var_or_else`], but bounded by [`FromEnvString`].
+    ///
+    /// # Leaks
+    /// This function will leak the parsed or the default value.
+    pub fn evar_or_else<T: FromEnvString, V: FnOnce() -> T>(
+        &self,
+        name: &str,
+        default: V,
+    ) -> &'static T {
+        self.evar_or(name, Box::leak(default().into()))
+    }
+}
+
+#[cfg(feature = "structured-values")]
+impl<G, I: Fn(&str) -> Result<String, G>> EnvGetter<G, I> {
+    /// Parses the raw environment variable as a structured literal (see
+    /// [`EnvValue`]) instead of relying on [`FromStr`]. Useful for arrays,
+    /// dicts, bools and numbers without writing a custom `FromStr` impl,
+    /// e.g. `APP_LIMITS=[10, 20, 30]` or `DB={host="x", port=5432}`.
+    ///
+    /// # Errors
+    /// When the environment variable is not found or its value isn't valid
+    /// structured-literal syntax.
+    pub fn owned_struct_var_try(
+        &self,
+        name: &str,
+    ) -> Result<EnvValue, EnvError<G, ParseError>> {
+        let var = (self.getter)(name).map_err(EnvError::GetterError)?;
+        struct_value::parse(&var).map_err(EnvError::ParseError)
+    }
 }
 
 /// This trait is used to create a new environment struct.
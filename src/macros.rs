@@ -0,0 +1,286 @@
+//! The [`env_config!`] macro generates an [`Env`](crate::Env) struct from a
+//! concise field list, so callers don't have to hand-write a `fn new()` like
+//! [the tests' `DummyEnv`](../tests/index.html) does.
+
+/// Builds a struct implementing [`Env`](crate::Env) from a concise field list.
+///
+/// Every field, including the last, must end with a trailing comma.
+///
+/// ```ignore
+/// env_config! {
+///     struct AppEnv {
+///         PORT: u16 = 8080,
+///         HOST: String,
+///         DEBUG: Option<bool>,
+///         #[env = "APP_VERBOSE"]
+///         VERBOSE: bool = false,
+///         db: DbEnv {
+///             URL: String,
+///         },
+///     }
+/// }
+/// ```
+///
+/// - A field with `= default` expands to [`EnvGetter::owned_var_or`](crate::EnvGetter::owned_var_or).
+/// - A plain field expands to [`EnvGetter::owned_var`](crate::EnvGetter::owned_var) (panics if missing).
+/// - An `Option<T>` field expands to `owned_var_try(name).ok()`.
+/// - `#[env = "NAME"]` overrides the env-var name for that field; otherwise the
+///   field's own identifier is used, prefixed by any enclosing namespace
+///   (e.g. `db { URL: String }` reads `DB_URL`).
+/// - A nested `field: Type { .. }` block generates its own `Type` struct/impl
+///   and namespaces its fields' env-var names under the uppercased field name.
+///
+/// By default variables are read via [`std::env::var`]. Pass a custom getter
+/// (matching [`EnvGetter::new`](crate::EnvGetter::new)'s closure) with
+/// `env_config! { getter: my_getter, struct AppEnv { .. } }` so it also works
+/// against custom backends (e.g. a test map).
+#[macro_export]
+macro_rules! env_config {
+    (getter: $getter:expr, struct $name:ident { $($body:tt)* }) => {
+        $crate::__env_config_struct! {
+            getter = ($getter),
+            prefix_idents = [],
+            name = $name,
+            struct_fields = [],
+            inits = [],
+            nested = [],
+            rest = { $($body)* }
+        }
+    };
+    (struct $name:ident { $($body:tt)* }) => {
+        $crate::env_config! {
+            getter: |name: &str| ::std::env::var(name),
+            struct $name { $($body)* }
+        }
+    };
+}
+
+/// Internal TT-muncher behind [`env_config!`]. Not part of the public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __env_config_struct {
+    // Base case: no fields left, emit the struct, its `Env` impl, and any
+    // nested structs collected while munching.
+    (
+        getter = ($getter:expr),
+        prefix_idents = [$($ns:ident)*],
+        name = $name:ident,
+        struct_fields = [$($sf:tt)*],
+        inits = [$($init:tt)*],
+        nested = [$($nested:tt)*],
+        rest = {}
+    ) => {
+        #[derive(Debug)]
+        #[allow(non_snake_case)]
+        pub struct $name {
+            $($sf)*
+        }
+
+        impl $crate::Env for $name {
+            fn new() -> Self {
+                Self {
+                    $($init)*
+                }
+            }
+        }
+
+        $($nested)*
+    };
+
+    // Field with an explicit `#[env = "NAME"]` override and a default.
+    (
+        getter = ($getter:expr),
+        prefix_idents = [$($ns:ident)*],
+        name = $name:ident,
+        struct_fields = [$($sf:tt)*],
+        inits = [$($init:tt)*],
+        nested = [$($nested:tt)*],
+        rest = { #[env = $env_name:literal] $field:ident : $ty:ty = $default:expr, $($rest:tt)* }
+    ) => {
+        $crate::__env_config_struct! {
+            getter = ($getter),
+            prefix_idents = [$($ns)*],
+            name = $name,
+            struct_fields = [$($sf)* pub $field: $ty,],
+            inits = [$($init)* $field: $crate::EnvGetter::new($getter).owned_var_or($env_name, $default),],
+            nested = [$($nested)*],
+            rest = { $($rest)* }
+        }
+    };
+
+    // `Option<T>` field with an explicit `#[env = "NAME"]` override.
+    (
+        getter = ($getter:expr),
+        prefix_idents = [$($ns:ident)*],
+        name = $name:ident,
+        struct_fields = [$($sf:tt)*],
+        inits = [$($init:tt)*],
+        nested = [$($nested:tt)*],
+        rest = { #[env = $env_name:literal] $field:ident : Option<$inner:ty>, $($rest:tt)* }
+    ) => {
+        $crate::__env_config_struct! {
+            getter = ($getter),
+            prefix_idents = [$($ns)*],
+            name = $name,
+            struct_fields = [$($sf)* pub $field: ::std::option::Option<$inner>,],
+            inits = [$($init)* $field: $crate::EnvGetter::new($getter).owned_var_try($env_name).ok(),],
+            nested = [$($nested)*],
+            rest = { $($rest)* }
+        }
+    };
+
+    // Field with an explicit `#[env = "NAME"]` override, no default.
+    (
+        getter = ($getter:expr),
+        prefix_idents = [$($ns:ident)*],
+        name = $name:ident,
+        struct_fields = [$($sf:tt)*],
+        inits = [$($init:tt)*],
+        nested = [$($nested:tt)*],
+        rest = { #[env = $env_name:literal] $field:ident : $ty:ty, $($rest:tt)* }
+    ) => {
+        $crate::__env_config_struct! {
+            getter = ($getter),
+            prefix_idents = [$($ns)*],
+            name = $name,
+            struct_fields = [$($sf)* pub $field: $ty,],
+            inits = [$($init)* $field: $crate::EnvGetter::new($getter).owned_var($env_name),],
+            nested = [$($nested)*],
+            rest = { $($rest)* }
+        }
+    };
+
+    // `Option<T>` field, no default: `owned_var_try(name).ok()`.
+    (
+        getter = ($getter:expr),
+        prefix_idents = [$($ns:ident)*],
+        name = $name:ident,
+        struct_fields = [$($sf:tt)*],
+        inits = [$($init:tt)*],
+        nested = [$($nested:tt)*],
+        rest = { $field:ident : Option<$inner:ty>, $($rest:tt)* }
+    ) => {
+        $crate::__env_config_struct! {
+            getter = ($getter),
+            prefix_idents = [$($ns)*],
+            name = $name,
+            struct_fields = [$($sf)* pub $field: ::std::option::Option<$inner>,],
+            inits = [$($init)* $field: $crate::EnvGetter::new($getter).owned_var_try(
+                &$crate::__env_config_name(
+                    &$crate::__env_config_prefix(&[$(::std::stringify!($ns)),*]),
+                    ::std::stringify!($field),
+                )
+            ).ok(),],
+            nested = [$($nested)*],
+            rest = { $($rest)* }
+        }
+    };
+
+    // Field with a default.
+    (
+        getter = ($getter:expr),
+        prefix_idents = [$($ns:ident)*],
+        name = $name:ident,
+        struct_fields = [$($sf:tt)*],
+        inits = [$($init:tt)*],
+        nested = [$($nested:tt)*],
+        rest = { $field:ident : $ty:ty = $default:expr, $($rest:tt)* }
+    ) => {
+        $crate::__env_config_struct! {
+            getter = ($getter),
+            prefix_idents = [$($ns)*],
+            name = $name,
+            struct_fields = [$($sf)* pub $field: $ty,],
+            inits = [$($init)* $field: $crate::EnvGetter::new($getter).owned_var_or(
+                &$crate::__env_config_name(
+                    &$crate::__env_config_prefix(&[$(::std::stringify!($ns)),*]),
+                    ::std::stringify!($field),
+                ),
+                $default
+            ),],
+            nested = [$($nested)*],
+            rest = { $($rest)* }
+        }
+    };
+
+    // Plain required field.
+    (
+        getter = ($getter:expr),
+        prefix_idents = [$($ns:ident)*],
+        name = $name:ident,
+        struct_fields = [$($sf:tt)*],
+        inits = [$($init:tt)*],
+        nested = [$($nested:tt)*],
+        rest = { $field:ident : $ty:ty, $($rest:tt)* }
+    ) => {
+        $crate::__env_config_struct! {
+            getter = ($getter),
+            prefix_idents = [$($ns)*],
+            name = $name,
+            struct_fields = [$($sf)* pub $field: $ty,],
+            inits = [$($init)* $field: $crate::EnvGetter::new($getter).owned_var(
+                &$crate::__env_config_name(
+                    &$crate::__env_config_prefix(&[$(::std::stringify!($ns)),*]),
+                    ::std::stringify!($field),
+                )
+            ),],
+            nested = [$($nested)*],
+            rest = { $($rest)* }
+        }
+    };
+
+    // Nested namespace: generates its own struct/impl and recurses into it
+    // with the namespace field appended to the prefix chain.
+    (
+        getter = ($getter:expr),
+        prefix_idents = [$($ns:ident)*],
+        name = $name:ident,
+        struct_fields = [$($sf:tt)*],
+        inits = [$($init:tt)*],
+        nested = [$($nested:tt)*],
+        rest = { $ns_field:ident : $ns_ty:ident { $($ns_body:tt)* }, $($rest:tt)* }
+    ) => {
+        $crate::__env_config_struct! {
+            getter = ($getter),
+            prefix_idents = [$($ns)*],
+            name = $name,
+            struct_fields = [$($sf)* pub $ns_field: $ns_ty,],
+            inits = [$($init)* $ns_field: <$ns_ty as $crate::Env>::new(),],
+            nested = [
+                $($nested)*
+                $crate::__env_config_struct! {
+                    getter = ($getter),
+                    prefix_idents = [$($ns)* $ns_field],
+                    name = $ns_ty,
+                    struct_fields = [],
+                    inits = [],
+                    nested = [],
+                    rest = { $($ns_body)* }
+                }
+            ],
+            rest = { $($rest)* }
+        }
+    };
+}
+
+/// Joins namespace identifiers (each uppercased) with `_`. Used by
+/// [`env_config!`] to build a nested field's env-var prefix at runtime, since
+/// declarative macros can't change an identifier's case at compile time.
+#[doc(hidden)]
+pub fn __env_config_prefix(parts: &[&str]) -> String {
+    parts
+        .iter()
+        .map(|part| part.to_uppercase())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Prefixes `field` with `prefix` (if any) to build the full env-var name.
+#[doc(hidden)]
+pub fn __env_config_name(prefix: &str, field: &str) -> String {
+    if prefix.is_empty() {
+        field.to_string()
+    } else {
+        format!("{prefix}_{field}")
+    }
+}
@@ -0,0 +1,252 @@
+//! Structured env value parsing, gated behind the `structured-values` feature
+//! so it is only compiled when that feature is enabled.
+//!
+//! Inspired by figment's `Env` provider syntax: a single variable can hold a
+//! bool, a number, a quoted string, a `[..]` array or a `{k=v}` dict, instead
+//! of requiring a custom [`FromStr`](std::str::FromStr) impl.
+
+use std::collections::BTreeMap;
+
+/// A value parsed from a single environment variable using the
+/// structured-literal syntax. See [`crate::EnvGetter::owned_struct_var_try`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnvValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Array(Vec<EnvValue>),
+    Dict(BTreeMap<String, EnvValue>),
+}
+
+/// Error returned when a raw environment value doesn't conform to the
+/// structured-literal syntax.
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum ParseError {
+    #[error("unterminated string literal: {0}")]
+    UnterminatedString(String),
+    #[error("invalid escape sequence in string literal: \\{0}")]
+    InvalidEscape(char),
+    #[error("invalid unicode escape sequence: \\u{0}")]
+    InvalidUnicodeEscape(String),
+    #[error("dict entry missing '=': {0}")]
+    MissingDictSeparator(String),
+    #[error("empty value")]
+    EmptyValue,
+}
+
+/// Parses a raw string into an [`EnvValue`] by classifying its shape.
+pub(crate) fn parse(raw: &str) -> Result<EnvValue, ParseError> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Err(ParseError::EmptyValue);
+    }
+
+    if trimmed == "true" {
+        return Ok(EnvValue::Bool(true));
+    }
+    if trimmed == "false" {
+        return Ok(EnvValue::Bool(false));
+    }
+
+    if let Some(inner) = strip_wrap(trimmed, '[', ']') {
+        let items = split_top_level(inner, ',')
+            .into_iter()
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty())
+            .map(parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        return Ok(EnvValue::Array(items));
+    }
+
+    if let Some(inner) = strip_wrap(trimmed, '{', '}') {
+        let mut dict = BTreeMap::new();
+        for entry in split_top_level(inner, ',') {
+            let entry = entry.trim();
+            if entry.is_empty() {
+                continue;
+            }
+            let (key, value) = entry
+                .split_once('=')
+                .ok_or_else(|| ParseError::MissingDictSeparator(entry.to_string()))?;
+            dict.insert(key.trim().to_string(), parse(value.trim())?);
+        }
+        return Ok(EnvValue::Dict(dict));
+    }
+
+    if let Some(inner) = trimmed.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        return unescape(inner).map(EnvValue::Str);
+    }
+
+    if let Ok(int) = trimmed.parse::<i64>() {
+        return Ok(EnvValue::Int(int));
+    }
+
+    if trimmed.contains('.') && let Ok(float) = trimmed.parse::<f64>() {
+        return Ok(EnvValue::Float(float));
+    }
+
+    Ok(EnvValue::Str(trimmed.to_string()))
+}
+
+/// Strips a leading `open` and trailing `close` from `s`, if both are present.
+fn strip_wrap(s: &str, open: char, close: char) -> Option<&str> {
+    let rest = s.strip_prefix(open)?;
+    rest.strip_suffix(close)
+}
+
+/// Splits `s` on `sep`, ignoring separators nested inside `[..]`/`{..}` or a
+/// quoted string.
+fn split_top_level(s: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut start = 0;
+
+    for (i, ch) in s.char_indices() {
+        if in_string {
+            match ch {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match ch {
+            '"' => in_string = true,
+            '[' | '{' => depth += 1,
+            ']' | '}' => depth -= 1,
+            c if c == sep && depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+/// Resolves `\n \t \r \" \\ \uXXXX` escapes inside a quoted string's contents.
+fn unescape(s: &str) -> Result<String, ParseError> {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some('"') => out.push('"'),
+            Some('\\') => out.push('\\'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                let code = u32::from_str_radix(&hex, 16)
+                    .map_err(|_| ParseError::InvalidUnicodeEscape(hex.clone()))?;
+                let unescaped =
+                    char::from_u32(code).ok_or(ParseError::InvalidUnicodeEscape(hex))?;
+                out.push(unescaped);
+            }
+            Some(other) => return Err(ParseError::InvalidEscape(other)),
+            None => return Err(ParseError::UnterminatedString(s.to_string())),
+        }
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bool() {
+        assert_eq!(parse("true").unwrap(), EnvValue::Bool(true));
+        assert_eq!(parse("false").unwrap(), EnvValue::Bool(false));
+    }
+
+    #[test]
+    fn test_parse_int() {
+        assert_eq!(parse("42").unwrap(), EnvValue::Int(42));
+        assert_eq!(parse("-7").unwrap(), EnvValue::Int(-7));
+    }
+
+    #[test]
+    fn test_parse_float() {
+        assert_eq!(parse("4.2").unwrap(), EnvValue::Float(4.2));
+    }
+
+    #[test]
+    fn test_parse_bare_str() {
+        assert_eq!(
+            parse("paypal").unwrap(),
+            EnvValue::Str("paypal".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_quoted_str_with_escapes() {
+        assert_eq!(
+            parse(r#""hi\n\t\"there\"""#).unwrap(),
+            EnvValue::Str("hi\n\t\"there\"".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_quoted_str_with_unicode_escape() {
+        assert_eq!(
+            parse(r#""\u0041\u0042""#).unwrap(),
+            EnvValue::Str("AB".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_array() {
+        assert_eq!(
+            parse("[10, 20, 30]").unwrap(),
+            EnvValue::Array(vec![EnvValue::Int(10), EnvValue::Int(20), EnvValue::Int(30)])
+        );
+    }
+
+    #[test]
+    fn test_parse_nested_array() {
+        assert_eq!(
+            parse("[1, [2, 3]]").unwrap(),
+            EnvValue::Array(vec![
+                EnvValue::Int(1),
+                EnvValue::Array(vec![EnvValue::Int(2), EnvValue::Int(3)]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_dict() {
+        let dict = match parse(r#"{host="x", port=5432}"#).unwrap() {
+            EnvValue::Dict(dict) => dict,
+            other => panic!("expected Dict, got {other:?}"),
+        };
+        assert_eq!(dict.get("host"), Some(&EnvValue::Str("x".to_string())));
+        assert_eq!(dict.get("port"), Some(&EnvValue::Int(5432)));
+    }
+
+    #[test]
+    fn test_parse_dict_missing_separator() {
+        assert!(matches!(
+            parse("{host}"),
+            Err(ParseError::MissingDictSeparator(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_empty_value_errors() {
+        assert!(matches!(parse(""), Err(ParseError::EmptyValue)));
+    }
+}
@@ -6,48 +6,55 @@ fn mock_getter(key: &str) -> Result<String, std::env::VarError> {
         "INT_OK" => Ok("42".to_string()),
         "INT_BAD" => Ok("not_an_int".to_string()),
         "MISSING" => Err(std::env::VarError::NotPresent),
+        "VEC_OK" => Ok("1, 2, 3".to_string()),
+        "VEC_EMPTY" => Ok("".to_string()),
+        "VEC_WHITESPACE" => Ok("1,, 2,  ,3".to_string()),
+        "VEC_BAD" => Ok("1,not_an_int,3".to_string()),
+        "DB_URL" => Ok("postgres://localhost".to_string()),
+        "PLATFORM_MIXED_CASE" => Ok("PayPal".to_string()),
+        "PLATFORM_UNKNOWN" => Ok("venmo".to_string()),
         _ => panic!("Unexpected key"),
     }
 }
 
 #[test]
 fn test_owned_var_try_ok() {
-    let getter = EnvGetter::init_env(mock_getter);
+    let getter = EnvGetter::new(mock_getter);
     let result: Result<i32, _> = getter.owned_var_try("INT_OK");
     assert_eq!(result.unwrap(), 42);
 }
 
 #[test]
 fn test_owned_var_try_missing() {
-    let getter = EnvGetter::init_env(mock_getter);
+    let getter = EnvGetter::new(mock_getter);
     let result: Result<i32, _> = getter.owned_var_try("MISSING");
     assert!(matches!(result, Err(EnvError::GetterError(_))));
 }
 
 #[test]
 fn test_owned_var_try_parse_fail() {
-    let getter = EnvGetter::init_env(mock_getter);
+    let getter = EnvGetter::new(mock_getter);
     let result: Result<i32, _> = getter.owned_var_try("INT_BAD");
     assert!(matches!(result, Err(EnvError::ParseError(_))));
 }
 
 #[test]
 fn test_owned_var_or_default_used() {
-    let getter = EnvGetter::init_env(mock_getter);
+    let getter = EnvGetter::new(mock_getter);
     let val: i32 = getter.owned_var_or("MISSING", 123);
     assert_eq!(val, 123);
 }
 
 #[test]
 fn test_owned_var_or_default_skipped() {
-    let getter = EnvGetter::init_env(mock_getter);
+    let getter = EnvGetter::new(mock_getter);
     let val: i32 = getter.owned_var_or("INT_OK", 123);
     assert_eq!(val, 42);
 }
 
 #[test]
 fn test_owned_var_or_else_called() {
-    let getter = EnvGetter::init_env(mock_getter);
+    let getter = EnvGetter::new(mock_getter);
     let val: i32 = getter.owned_var_or_else("MISSING", || 999);
     assert_eq!(val, 999);
 }
@@ -55,24 +62,116 @@ fn test_owned_var_or_else_called() {
 #[test]
 #[should_panic(expected = "Couldn't find or parse env variable INT_BAD")]
 fn test_owned_var_panics_on_parse_error() {
-    let getter = EnvGetter::init_env(mock_getter);
+    let getter = EnvGetter::new(mock_getter);
     let _: i32 = getter.owned_var("INT_BAD");
 }
 
 #[test]
 fn test_var_try_leak() {
-    let getter = EnvGetter::init_env(mock_getter);
+    let getter = EnvGetter::new(mock_getter);
     let val: &'static i32 = getter.var_try("INT_OK").unwrap();
     assert_eq!(*val, 42);
 }
 
 #[test]
 fn test_var_or_else_leak() {
-    let getter = EnvGetter::init_env(mock_getter);
+    let getter = EnvGetter::new(mock_getter);
     let val: &'static i32 = getter.var_or_else("MISSING", || 999);
     assert_eq!(*val, 999);
 }
 
+#[test]
+fn test_owned_vec_var_try_ok() {
+    let getter = EnvGetter::new(mock_getter);
+    let result: Result<Vec<i32>, _> = getter.owned_vec_var_try("VEC_OK", ",");
+    assert_eq!(result.unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_owned_vec_var_try_empty_is_empty_vec() {
+    let getter = EnvGetter::new(mock_getter);
+    let result: Result<Vec<i32>, _> = getter.owned_vec_var_try("VEC_EMPTY", ",");
+    assert_eq!(result.unwrap(), Vec::<i32>::new());
+}
+
+#[test]
+fn test_owned_vec_var_try_skips_whitespace_segments() {
+    let getter = EnvGetter::new(mock_getter);
+    let result: Result<Vec<i32>, _> = getter.owned_vec_var_try("VEC_WHITESPACE", ",");
+    assert_eq!(result.unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_owned_vec_var_try_missing() {
+    let getter = EnvGetter::new(mock_getter);
+    let result: Result<Vec<i32>, _> = getter.owned_vec_var_try("MISSING", ",");
+    assert!(matches!(result, Err(EnvError::GetterError(_))));
+}
+
+#[test]
+fn test_owned_vec_var_try_parse_fail() {
+    let getter = EnvGetter::new(mock_getter);
+    let result: Result<Vec<i32>, _> = getter.owned_vec_var_try("VEC_BAD", ",");
+    assert!(matches!(result, Err(EnvError::ParseError(_))));
+}
+
+#[test]
+fn test_owned_vec_var_or_default_used() {
+    let getter = EnvGetter::new(mock_getter);
+    let val: Vec<i32> = getter.owned_vec_var_or("MISSING", ",", vec![9]);
+    assert_eq!(val, vec![9]);
+}
+
+#[test]
+#[should_panic(expected = "Couldn't find or parse env variable VEC_BAD")]
+fn test_owned_vec_var_panics_on_parse_error() {
+    let getter = EnvGetter::new(mock_getter);
+    let _: Vec<i32> = getter.owned_vec_var("VEC_BAD", ",");
+}
+
+#[test]
+fn test_vec_var_try_leak() {
+    let getter = EnvGetter::new(mock_getter);
+    let val: &'static [i32] = getter.vec_var_try("VEC_OK", ",").unwrap();
+    assert_eq!(val, &[1, 2, 3]);
+}
+
+#[test]
+fn test_vec_var_or_default_used() {
+    let getter = EnvGetter::new(mock_getter);
+    let val: &'static [i32] = getter.vec_var_or("MISSING", ",", &[7, 8]);
+    assert_eq!(val, &[7, 8]);
+}
+
+#[test]
+fn test_owned_var_or_set_with_uses_existing_value() {
+    let getter = EnvGetter::new(mock_getter);
+    let mut writes = Vec::new();
+    let val: i32 = getter.owned_var_or_set_with("INT_OK", 123, |name, value| {
+        writes.push((name.to_string(), value.to_string()));
+    });
+    assert_eq!(val, 42);
+    assert!(writes.is_empty());
+}
+
+#[test]
+fn test_owned_var_or_set_with_writes_default_when_missing() {
+    let getter = EnvGetter::new(mock_getter);
+    let mut writes = Vec::new();
+    let val: i32 = getter.owned_var_or_set_with("MISSING", 123, |name, value| {
+        writes.push((name.to_string(), value.to_string()));
+    });
+    assert_eq!(val, 123);
+    assert_eq!(writes, vec![("MISSING".to_string(), "123".to_string())]);
+}
+
+#[test]
+#[should_panic(expected = "Couldn't find or parse env variable INT_BAD")]
+fn test_owned_var_or_set_with_panics_on_parse_error() {
+    let getter = EnvGetter::new(mock_getter);
+    let _: i32 = getter.owned_var_or_set_with("INT_BAD", 123, |_, _| {});
+}
+
 #[derive(Debug)]
 struct DummyEnv {
     some_int: i32,
@@ -84,7 +183,7 @@ struct DummyEnv {
 
 impl Env for DummyEnv {
     fn new() -> Self {
-        let g = EnvGetter::init_env(mock_getter);
+        let g = EnvGetter::new(mock_getter);
 
         Self {
             some_int: g.owned_var("INT_OK"),
@@ -99,7 +198,7 @@ impl Env for DummyEnv {
 #[test]
 fn test_env_lazy_deref() {
     let env = EnvLazy::<DummyEnv>::new();
-    let init: &DummyEnv = &*env;
+    let init: &DummyEnv = &env;
 
     assert_eq!(init.some_int, 42);
     assert_eq!(init.some_other_int, 100);
@@ -112,7 +211,7 @@ fn test_env_lazy_deref() {
 fn test_env_once_deref_after_init() {
     let env = EnvOnce::<DummyEnv>::new();
     env.init();
-    let init: &DummyEnv = &*env;
+    let init: &DummyEnv = &env;
 
     assert_eq!(init.some_int, 42);
     assert_eq!(init.some_other_int, 100);
@@ -135,3 +234,92 @@ fn test_env_once_double_init_panics() {
     env.init();
     env.init(); // Should panic on second init
 }
+
+crate::env_config! {
+    getter: mock_getter,
+    struct MacroEnv {
+        STRING: String,
+        INT_BAD: i32 = 7,
+        #[env = "MISSING"]
+        opt_int: Option<i32>,
+        #[env = "STRING"]
+        renamed: String,
+        db: DbEnv {
+            URL: String,
+        },
+    }
+}
+
+#[test]
+fn test_env_config_macro() {
+    let env = MacroEnv::new();
+
+    assert_eq!(env.STRING, "Hello");
+    assert_eq!(env.INT_BAD, 7);
+    assert_eq!(env.opt_int, None);
+    assert_eq!(env.renamed, "Hello");
+    assert_eq!(env.db.URL, "postgres://localhost");
+}
+
+#[derive(Debug, PartialEq)]
+enum Platform {
+    PayPal,
+    Stripe,
+}
+
+#[derive(Debug, PartialEq)]
+struct UnknownPlatform(String);
+
+impl FromEnvString for Platform {
+    type Err = UnknownPlatform;
+
+    fn from_env_string(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "paypal" => Ok(Platform::PayPal),
+            "stripe" => Ok(Platform::Stripe),
+            other => Err(UnknownPlatform(other.to_string())),
+        }
+    }
+}
+
+#[test]
+fn test_owned_evar_try_custom_impl_is_case_insensitive() {
+    let getter = EnvGetter::new(mock_getter);
+    let result = getter.owned_evar_try::<Platform>("PLATFORM_MIXED_CASE");
+    assert_eq!(result.unwrap(), Platform::PayPal);
+}
+
+#[test]
+fn test_owned_evar_try_parse_fail() {
+    let getter = EnvGetter::new(mock_getter);
+    let result = getter.owned_evar_try::<Platform>("PLATFORM_UNKNOWN");
+    assert!(matches!(result, Err(EnvError::ParseError(_))));
+}
+
+#[test]
+fn test_owned_evar_or_default_used() {
+    let getter = EnvGetter::new(mock_getter);
+    let val = getter.owned_evar_or("MISSING", Platform::Stripe);
+    assert_eq!(val, Platform::Stripe);
+}
+
+#[test]
+fn test_owned_evar_falls_back_to_from_str_blanket_impl() {
+    let getter = EnvGetter::new(mock_getter);
+    let val: i32 = getter.owned_evar("INT_OK");
+    assert_eq!(val, 42);
+}
+
+#[test]
+fn test_evar_try_leak() {
+    let getter = EnvGetter::new(mock_getter);
+    let val: &'static Platform = getter.evar_try("PLATFORM_MIXED_CASE").unwrap();
+    assert_eq!(*val, Platform::PayPal);
+}
+
+#[test]
+fn test_evar_or_else_leak() {
+    let getter = EnvGetter::new(mock_getter);
+    let val: &'static Platform = getter.evar_or_else("MISSING", || Platform::Stripe);
+    assert_eq!(*val, Platform::Stripe);
+}